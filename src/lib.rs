@@ -63,6 +63,30 @@ enum Idle {
     Yes
 }
 
+/// Controls how the event loop schedules updates and renders.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Drives updates and renders continuously at the configured
+    /// `ups`/`max_fps` rates, as fast as the rates allow.
+    RefreshSync,
+    /// Blocks waiting for an input event or an explicit `wake`, and only
+    /// then runs a single update/render pass. Suited to GUI-style
+    /// applications that are idle most of the time.
+    Wait,
+}
+
+/// How often the waiting loop falls back to polling the window for
+/// events when the `Window` back-end has no blocking wait.
+const WAIT_POLL_INTERVAL_MS: u32 = 16;
+
+/// Window back-ends that can synchronize buffer swaps to the display's
+/// vertical retrace, as an alternative to software frame pacing.
+pub trait SwapIntervalWindow: Window {
+    /// Sets the swap interval: `1` to sync swaps to vsync, `0` to
+    /// disable and swap as soon as the buffer is ready.
+    fn set_swap_interval(&mut self, interval: u32);
+}
+
 #[derive(Copy, Clone, Debug)]
 enum State {
     Render,
@@ -118,23 +142,92 @@ pub struct Events<W, E>
     state: State,
     last_update: u64,
     last_frame: u64,
-    dt_update_in_ns: u64,
-    dt_frame_in_ns: u64,
+    dt_update_in_ns: f64,
+    dt_frame_in_ns: f64,
+    update_carry_ns: f64,
     dt: f64,
     swap_buffers: bool,
+    loop_mode: LoopMode,
+    wake_requested: bool,
+    max_updates_per_frame: u32,
+    updates_since_render: u32,
+    vsync: bool,
+    last_update_time: u64,
+    avg_frame_time: Option<f64>,
+    avg_update_time: Option<f64>,
+    spin_margin_ns: u64,
+    spin_enabled: bool,
     _marker_e: PhantomData<E>,
 }
 
 static BILLION: u64 = 1_000_000_000;
 
+/// Smoothing factor for the `current_fps`/`current_ups` exponential
+/// moving averages. Higher values track recent samples more closely.
+const RATE_EMA_ALPHA: f64 = 0.2;
+
 fn ns_to_ms(ns: u64) -> u32 {
     (ns / 1_000_000) as u32
 }
 
+/// Computes the elapsed nanoseconds from `baseline` to `now`, guarding
+/// against a non-monotonic clock.
+///
+/// Some platforms' precise timers are not strictly monotonic, so `now`
+/// can read earlier than `baseline`. A plain `u64` subtraction would
+/// then panic (debug) or wrap to a huge value (release). When that
+/// happens, treat the elapsed time as zero and reset `baseline` to `now`
+/// so the anomaly doesn't keep producing bogus deltas.
+fn elapsed_ns(baseline: &mut u64, now: u64) -> u64 {
+    if now >= *baseline {
+        now - *baseline
+    } else {
+        *baseline = now;
+        0
+    }
+}
+
+/// Advances `baseline` by `period_ns`, accumulating the fractional
+/// nanosecond that doesn't fit in the integer baseline into `carry_ns`
+/// so it is folded into a later tick instead of being lost to rounding.
+/// This keeps the long-run average rate exact even for periods like
+/// 59.94 Hz that have no whole-nanosecond period.
+fn advance_by_period(baseline: &mut u64, period_ns: f64, carry_ns: &mut f64) {
+    let total = period_ns + *carry_ns;
+    let whole = total.trunc();
+    *carry_ns = total - whole;
+    *baseline += whole as u64;
+}
+
+/// Sleeps for approximately `ns` nanoseconds.
+///
+/// `sleep_ms` rounds down to whole milliseconds and inherits the OS
+/// scheduler's multi-millisecond wakeup granularity, which at 60+ FPS
+/// either oversleeps past the target or discards up to a millisecond of
+/// the interval. Instead, sleep for the bulk of the interval, leaving
+/// `spin_margin_ns` unslept, then busy-spin on the precise clock until
+/// the target is reached. Pass `spin_enabled: false` to fall back to a
+/// plain `sleep_ms`, trading pacing precision for not spinning a core.
+fn precise_sleep(ns: u64, spin_margin_ns: u64, spin_enabled: bool) {
+    if !spin_enabled {
+        sleep_ms(ns_to_ms(ns));
+        return;
+    }
+    let target = clock_ticks::precise_time_ns() + ns;
+    if ns > spin_margin_ns {
+        sleep_ms(ns_to_ms(ns - spin_margin_ns));
+    }
+    while clock_ticks::precise_time_ns() < target {}
+}
+
 /// The default updates per second.
 pub const DEFAULT_UPS: u64 = 120;
 /// The default maximum frames per second.
 pub const DEFAULT_MAX_FPS: u64 = 60;
+/// The default maximum number of updates to run before forcing a render.
+pub const DEFAULT_MAX_UPDATES_PER_FRAME: u32 = 10;
+/// The default safety margin left unslept for `precise_sleep`'s busy-spin tail.
+pub const DEFAULT_SPIN_MARGIN_NS: u64 = 1_000_000;
 
 impl<W, E> Events<W, E>
     where
@@ -151,10 +244,21 @@ impl<W, E> Events<W, E>
             state: State::Render,
             last_update: start,
             last_frame: start,
-            dt_update_in_ns: BILLION / updates_per_second,
-            dt_frame_in_ns: BILLION / max_frames_per_second,
+            dt_update_in_ns: BILLION as f64 / updates_per_second as f64,
+            dt_frame_in_ns: BILLION as f64 / max_frames_per_second as f64,
+            update_carry_ns: 0.0,
             dt: 1.0 / updates_per_second as f64,
             swap_buffers: true,
+            loop_mode: LoopMode::RefreshSync,
+            wake_requested: false,
+            max_updates_per_frame: DEFAULT_MAX_UPDATES_PER_FRAME,
+            updates_since_render: 0,
+            vsync: false,
+            last_update_time: start,
+            avg_frame_time: None,
+            avg_update_time: None,
+            spin_margin_ns: DEFAULT_SPIN_MARGIN_NS,
+            spin_enabled: true,
             _marker_e: PhantomData,
         }
     }
@@ -164,8 +268,17 @@ impl<W, E> Events<W, E>
     /// This is the fixed update rate on average over time.
     /// If the event loop lags, it will try to catch up.
     pub fn ups(mut self, frames: u64) -> Self {
-        self.dt_update_in_ns = BILLION / frames;
-        self.dt = 1.0 / frames as f64;
+        self.ups_f64(frames as f64)
+    }
+
+    /// The number of updates per second, as a fractional rate.
+    ///
+    /// Use this over `ups` when the desired rate isn't a whole number,
+    /// e.g. to match a real display's refresh rate such as 59.94 Hz.
+    pub fn ups_f64(mut self, updates: f64) -> Self {
+        self.dt_update_in_ns = BILLION as f64 / updates;
+        self.update_carry_ns = 0.0;
+        self.dt = 1.0 / updates;
         self
     }
 
@@ -175,7 +288,16 @@ impl<W, E> Events<W, E>
     /// next frame is always scheduled from the previous frame.
     /// This causes the frames to "slip" over time.
     pub fn max_fps(mut self, frames: u64) -> Self {
-        self.dt_frame_in_ns = BILLION / frames;
+        self.max_fps_f64(frames as f64)
+    }
+
+    /// The maximum number of frames per second, as a fractional rate.
+    ///
+    /// Use this over `max_fps` when the desired rate isn't a whole
+    /// number, e.g. to match a real display's refresh rate such as
+    /// 59.94 Hz.
+    pub fn max_fps_f64(mut self, frames: f64) -> Self {
+        self.dt_frame_in_ns = BILLION as f64 / frames;
         self
     }
 
@@ -184,6 +306,100 @@ impl<W, E> Events<W, E>
         self.swap_buffers = enable;
         self
     }
+
+    /// Sets the loop mode, switching between a continuously refreshing
+    /// loop and one that waits for input events between passes.
+    pub fn loop_mode(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = loop_mode;
+        self
+    }
+
+    /// Wakes up a `Wait`-mode loop, causing it to run one more
+    /// update/render pass even though no input event has arrived.
+    ///
+    /// Has no effect in `RefreshSync` mode.
+    pub fn wake(&mut self) {
+        self.wake_requested = true;
+    }
+
+    /// Sets the maximum number of updates to run before forcing a
+    /// render, bounding how far the update loop is allowed to fall
+    /// behind before it drops the backlog instead of spiraling.
+    ///
+    /// Clamped to a minimum of `1`: `0` would force a render before any
+    /// update ever ran.
+    pub fn max_updates_per_frame(mut self, updates: u32) -> Self {
+        self.max_updates_per_frame = cmp::max(updates, 1);
+        self
+    }
+
+    /// Returns the measured frames per second, averaged over recent
+    /// frames.
+    pub fn current_fps(&self) -> f64 {
+        rate_from_avg_interval(self.avg_frame_time)
+    }
+
+    /// Returns the measured updates per second, averaged over recent
+    /// updates.
+    pub fn current_ups(&self) -> f64 {
+        rate_from_avg_interval(self.avg_update_time)
+    }
+
+    /// Sets the safety margin left unslept before `precise_sleep`
+    /// starts busy-spinning on the clock, tightening frame pacing at
+    /// the cost of a brief spin before each frame.
+    pub fn spin_margin_ns(mut self, ns: u64) -> Self {
+        self.spin_margin_ns = ns;
+        self
+    }
+
+    /// Enables or disables the busy-spin tail of `precise_sleep`.
+    ///
+    /// Disable for battery-sensitive contexts, trading a few
+    /// milliseconds of extra pacing jitter for not spinning a core.
+    pub fn spin(mut self, enable: bool) -> Self {
+        self.spin_enabled = enable;
+        self
+    }
+}
+
+/// Turns an averaged interval in seconds into a rate, guarding against
+/// there being no sample yet or a zero interval.
+fn rate_from_avg_interval(avg: Option<f64>) -> f64 {
+    match avg {
+        Some(avg) if avg > 0.0 => 1.0 / avg,
+        _ => 0.0,
+    }
+}
+
+/// Folds a new interval sample into a running exponential moving
+/// average, seeding the average with the first sample.
+fn update_ema(avg: Option<f64>, interval: f64) -> Option<f64> {
+    if interval <= 0.0 {
+        return avg;
+    }
+    Some(match avg {
+        Some(avg) => RATE_EMA_ALPHA * interval + (1.0 - RATE_EMA_ALPHA) * avg,
+        None => interval,
+    })
+}
+
+impl<W, E> Events<W, E>
+    where
+        W: SwapIntervalWindow,
+        E: EventMap<<W as Window>::Event>
+{
+    /// Enables or disables vsync.
+    ///
+    /// Tells the window back-end to synchronize buffer swaps to the
+    /// vertical retrace, and suppresses the software frame scheduler so
+    /// frames are rendered as fast as the display allows instead of
+    /// fighting it via `max_fps`.
+    pub fn vsync(mut self, enable: bool) -> Self {
+        self.window.borrow_mut().set_swap_interval(if enable { 1 } else { 0 });
+        self.vsync = enable;
+        self
+    }
 }
 
 impl<W, E> Iterator for Events<W, E>
@@ -201,7 +417,11 @@ impl<W, E> Iterator for Events<W, E>
                     if self.window.borrow().should_close() { return None; }
 
                     let start_render = clock_ticks::precise_time_ns();
+                    let frame_interval = elapsed_ns(&mut self.last_frame, start_render) as f64
+                                          / BILLION as f64;
+                    self.avg_frame_time = update_ema(self.avg_frame_time, frame_interval);
                     self.last_frame = start_render;
+                    self.updates_since_render = 0;
 
                     let size = self.window.borrow().size();
                     if size.width != 0 && size.height != 0 {
@@ -209,7 +429,7 @@ impl<W, E> Iterator for Events<W, E>
                         self.state = State::SwapBuffers;
                         return Some(EventMap::render(RenderArgs {
                             // Extrapolate time forward to allow smooth motion.
-                            ext_dt: (start_render - self.last_update) as f64
+                            ext_dt: elapsed_ns(&mut self.last_update, start_render) as f64
                                     / BILLION as f64,
                             width: size.width,
                             height: size.height,
@@ -228,25 +448,73 @@ impl<W, E> Iterator for Events<W, E>
                     }
                 }
                 State::UpdateLoop(ref mut idle) => {
-                    let current_time = clock_ticks::precise_time_ns();
-                    let next_frame = self.last_frame + self.dt_frame_in_ns;
-                    let next_update = self.last_update + self.dt_update_in_ns;
-                    let next_event = cmp::min(next_frame, next_update);
-                    if next_event > current_time {
+                    if self.loop_mode == LoopMode::Wait {
                         if let Some(x) = self.window.borrow_mut().poll_event() {
                             *idle = Idle::No;
+                            // An input event also counts as a reason to
+                            // run one update/render pass, same as an
+                            // explicit `wake`, once events drain.
+                            self.wake_requested = true;
                             return Some(EventMap::input(x));
-                        } else if *idle == Idle::No {
-                            *idle = Idle::Yes;
-                            let seconds = ((next_event - current_time) as f64) / (BILLION as f64);
-                            return Some(EventMap::idle(IdleArgs { dt: seconds }))
+                        } else if self.wake_requested {
+                            self.wake_requested = false;
+                            // Resync to the current time before the
+                            // single update/render pass below: without
+                            // this, `last_update` would still reflect
+                            // whenever the last pass ran, so `ext_dt`
+                            // would grow by the whole idle gap instead
+                            // of staying a fraction of a frame.
+                            self.last_update = clock_ticks::precise_time_ns();
+                            State::HandleEvents
+                        } else {
+                            // The `Window` back-end has no blocking wait,
+                            // so fall back to polling at a coarse
+                            // interval instead of scheduling from
+                            // `dt_update_in_ns`/`dt_frame_in_ns`.
+                            sleep_ms(WAIT_POLL_INTERVAL_MS);
+                            State::UpdateLoop(*idle)
                         }
-                        sleep_ms(ns_to_ms(next_event - current_time));
-                        State::UpdateLoop(Idle::No)
-                    } else if next_event == next_frame {
-                        State::Render
                     } else {
-                        State::HandleEvents
+                        let current_time = clock_ticks::precise_time_ns();
+                        // Unlike `last_update`, `last_frame` is reset to
+                        // the actual wall-clock time on every render
+                        // rather than accumulated from itself, so
+                        // rounding `dt_frame_in_ns` here only affects
+                        // when the next render is scheduled, not the
+                        // long-run average frame rate. No carry needed.
+                        let next_frame = self.last_frame + self.dt_frame_in_ns.round() as u64;
+                        let next_update = self.last_update + self.dt_update_in_ns.round() as u64;
+                        let next_event = cmp::min(next_frame, next_update);
+                        if self.updates_since_render >= self.max_updates_per_frame {
+                            // Too many updates ran without a render: the
+                            // loop can never catch up this way, so drop
+                            // the backlog and render now (the classic
+                            // "spiral of death" fix).
+                            self.last_update = current_time;
+                            self.update_carry_ns = 0.0;
+                            State::Render
+                        } else if self.vsync && next_update > current_time {
+                            // With vsync enabled, the back-end blocks in
+                            // `swap_buffers` until the vertical retrace,
+                            // so skip the software frame schedule and
+                            // render as soon as no update is due.
+                            State::Render
+                        } else if next_event > current_time {
+                            if let Some(x) = self.window.borrow_mut().poll_event() {
+                                *idle = Idle::No;
+                                return Some(EventMap::input(x));
+                            } else if *idle == Idle::No {
+                                *idle = Idle::Yes;
+                                let seconds = ((next_event - current_time) as f64) / (BILLION as f64);
+                                return Some(EventMap::idle(IdleArgs { dt: seconds }))
+                            }
+                            precise_sleep(next_event - current_time, self.spin_margin_ns, self.spin_enabled);
+                            State::UpdateLoop(Idle::No)
+                        } else if next_event == next_frame {
+                            State::Render
+                        } else {
+                            State::HandleEvents
+                        }
                     }
                 }
                 State::HandleEvents => {
@@ -257,8 +525,21 @@ impl<W, E> Iterator for Events<W, E>
                     }
                 }
                 State::Update => {
-                    self.state = State::UpdateLoop(Idle::No);
-                    self.last_update += self.dt_update_in_ns;
+                    let current_time = clock_ticks::precise_time_ns();
+                    let update_interval = elapsed_ns(&mut self.last_update_time, current_time) as f64
+                                           / BILLION as f64;
+                    self.avg_update_time = update_ema(self.avg_update_time, update_interval);
+                    self.last_update_time = current_time;
+
+                    advance_by_period(&mut self.last_update, self.dt_update_in_ns, &mut self.update_carry_ns);
+                    self.updates_since_render += 1;
+                    self.state = if self.loop_mode == LoopMode::Wait {
+                        // In `Wait` mode there is no frame schedule to
+                        // fall back on, so render right away.
+                        State::Render
+                    } else {
+                        State::UpdateLoop(Idle::No)
+                    };
                     return Some(EventMap::update(UpdateArgs{ dt: self.dt }));
                 }
             };